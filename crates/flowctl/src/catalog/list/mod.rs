@@ -19,10 +19,73 @@ pub struct List {
     #[clap(flatten)]
     pub data_plane_selector: DataPlaneSelector,
 
+    /// Transitively expand the result set into a self-contained "closure".
+    ///
+    /// After fetching the matched specs, walk their `reads_from`/`writes_to`
+    /// edges and pull in every referenced collection (and its upstream
+    /// captures) until no new names are discovered or `--closure-depth` rounds
+    /// have run. A walk that finishes within the depth cap yields a draft with
+    /// no dangling references, so `--models` output can be re-published or
+    /// validated on its own. The edges are always fetched, since they are
+    /// required to walk the graph, but the table only shows the "Reads From" /
+    /// "Writes To" columns when `--flows` is also given.
+    #[clap(long = "closure")]
+    pub closure: bool,
+    /// Maximum number of breadth-first expansion rounds performed by `--closure`.
+    #[clap(long = "closure-depth", default_value = "10")]
+    pub closure_depth: u32,
+
+    /// Browse the namespace one level at a time, like an S3 `ListObjects` call
+    /// with a delimiter. Names that contain the `--delimiter` beyond the active
+    /// prefix are collapsed into a single "folder" row (a common prefix)
+    /// instead of being listed individually, so a large tenant can be explored
+    /// one segment at a time without fetching every leaf spec.
+    #[clap(long = "folders")]
+    pub folders: bool,
+    /// Delimiter used to collapse common prefixes in `--folders` mode.
+    #[clap(long = "delimiter", default_value = "/")]
+    pub delimiter: String,
+
+    /// Export matched specs to an S3-compatible bucket, one object per catalog
+    /// name keyed by its path (e.g. `acmeCo/prod/anvils.capture.json`), with the
+    /// serialized model as the body. Objects are written as the pagination
+    /// stream yields them, so a whole tenant can be snapshotted with bounded
+    /// memory; with `--closure`, the full closure is fetched into memory before
+    /// any object is written. Implies `--models`.
+    #[clap(long = "export-s3", value_name = "s3://bucket/prefix")]
+    pub export_s3: Option<String>,
+    /// Endpoint URL of a non-AWS S3-compatible gateway for `--export-s3`.
+    #[clap(long = "export-s3-endpoint")]
+    pub export_s3_endpoint: Option<String>,
+    /// Region of the `--export-s3` bucket.
+    #[clap(long = "export-s3-region")]
+    pub export_s3_region: Option<String>,
+    /// Access key id for `--export-s3` (falls back to the ambient AWS credential chain).
+    #[clap(long = "export-s3-access-key-id", requires = "export_s3_secret_access_key")]
+    pub export_s3_access_key_id: Option<String>,
+    /// Secret access key for `--export-s3`.
+    #[clap(long = "export-s3-secret-access-key", requires = "export_s3_access_key_id")]
+    pub export_s3_secret_access_key: Option<String>,
+
+    /// Maximum number of prefixes/names to paginate concurrently.
+    ///
+    /// Each selector (every `--prefix` and the combined `--name` batch) is
+    /// paginated as an independent sub-stream; up to this many are in flight at
+    /// once, turning a sum-of-latencies wait into a max-of-latencies wait.
+    #[clap(long = "concurrency", default_value = "4")]
+    pub concurrency: usize,
+
     /// This option is not exposed as a CLI argument. It just allows us to skip
     /// fetching publication info in contexts where it's not necessary.
     #[clap(skip = true)]
     pub include_last_publication: bool,
+
+    /// This option is not exposed as a CLI argument. When set, a by-name query
+    /// that matches no live spec is not a hard error: the row is yielded with
+    /// no `live_spec`. It's used while expanding a `--closure`, where neighbors
+    /// that are mid-deletion are expected and are dropped by the walk.
+    #[clap(skip = false)]
+    pub tolerate_missing_names: bool,
 }
 
 #[derive(graphql_client::GraphQLQuery)]
@@ -36,22 +99,171 @@ pub struct List {
 struct ListLiveSpecsQuery;
 
 pub async fn do_list(ctx: &mut crate::CliContext, list_args: &List) -> anyhow::Result<()> {
+    if list_args.export_s3.is_some() {
+        return export_live_specs_to_s3(ctx, list_args.clone()).await;
+    }
     if list_args.include_models && ctx.get_output_type() == output::OutputType::Table {
         anyhow::bail!(
             "cannot output models as a table, must pass `--output json` or `--output yaml`"
         );
     }
-    let rows = fetch_live_specs(ctx, list_args.clone()).await?;
+    let (rows, prefixes) = fetch_live_specs_resolved(ctx, list_args.clone()).await?;
+
+    if !list_args.folders {
+        return ctx.write_all(rows, list_args.include_flows);
+    }
+    if list_args.delimiter.is_empty() {
+        anyhow::bail!("--delimiter must not be empty");
+    }
+
+    // Hierarchical browsing: collapse names that descend past the active prefix
+    // into folder rows, and emit leaves normally. `prefixes` are the ones
+    // actually used for the query, which may have been auto-discovered when no
+    // `--name`/`--prefix` was given, so we collapse against those rather than
+    // the (possibly empty) raw CLI argument.
+    let (common_prefixes, specs) = collapse_listing(rows, &prefixes, &list_args.delimiter);
+
+    match ctx.get_output_type() {
+        output::OutputType::Table => {
+            // Folder rows sort ahead of the leaf specs they contain.
+            let entries = common_prefixes
+                .into_iter()
+                .map(ListEntry::CommonPrefix)
+                .chain(specs.into_iter().map(ListEntry::Spec));
+            ctx.write_all(entries, list_args.include_flows)
+        }
+        output::OutputType::Json => {
+            let listing = FolderListing::new(common_prefixes, specs);
+            serde_json::to_writer_pretty(std::io::stdout(), &listing)?;
+            println!();
+            Ok(())
+        }
+        output::OutputType::Yaml => {
+            let listing = FolderListing::new(common_prefixes, specs);
+            serde_yaml::to_writer(std::io::stdout(), &listing)?;
+            Ok(())
+        }
+    }
+}
+
+/// A single row of a `--folders` listing: either a leaf spec or a collapsed
+/// common prefix ("folder").
+enum ListEntry {
+    Spec(list_live_specs_query::SelectRef),
+    CommonPrefix(String),
+}
+
+/// The structured (`--output json|yaml`) shape of a `--folders` listing, with
+/// collapsed common prefixes separated from the leaf specs, mirroring an S3
+/// `ListObjects` response.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FolderListing {
+    common_prefixes: Vec<String>,
+    specs: Vec<list_live_specs_query::SelectRef>,
+}
+
+impl FolderListing {
+    fn new(
+        common_prefixes: std::collections::BTreeSet<String>,
+        specs: Vec<list_live_specs_query::SelectRef>,
+    ) -> Self {
+        FolderListing {
+            common_prefixes: common_prefixes.into_iter().collect(),
+            specs,
+        }
+    }
+}
+
+/// Partitions `rows` into collapsed common prefixes and leaf specs using an S3
+/// `ListObjects`-style delimiter. For each name, the longest active prefix it
+/// falls under is stripped and the next `delimiter` is located: names with no
+/// further delimiter are leaves, while the rest collapse to `prefix + segment +
+/// delimiter`. Common prefixes are deduped via a [`BTreeSet`].
+///
+/// [`BTreeSet`]: std::collections::BTreeSet
+fn collapse_listing(
+    rows: Vec<list_live_specs_query::SelectRef>,
+    prefixes: &[String],
+    delimiter: &str,
+) -> (
+    std::collections::BTreeSet<String>,
+    Vec<list_live_specs_query::SelectRef>,
+) {
+    let mut common_prefixes = std::collections::BTreeSet::new();
+    let mut leaves = Vec::new();
+
+    for row in rows {
+        match collapse_name(row.catalog_name.as_str(), prefixes, delimiter) {
+            Some(common) => {
+                common_prefixes.insert(common);
+            }
+            None => leaves.push(row),
+        }
+    }
+    (common_prefixes, leaves)
+}
+
+/// Collapses a single `catalog_name` against the active `prefixes`. Returns the
+/// common-prefix folder the name belongs to, or `None` if the name is a leaf
+/// (no delimiter remains beyond the active prefix). See [`collapse_listing`].
+fn collapse_name(catalog_name: &str, prefixes: &[String], delimiter: &str) -> Option<String> {
+    // The name was matched by a prefix query, so strip the longest active
+    // prefix it starts under before looking for the next delimiter. When
+    // listing by name there may be no active prefix, in which case we collapse
+    // from the start of the name.
+    let base = prefixes
+        .iter()
+        .filter(|p| catalog_name.starts_with(p.as_str()))
+        .map(String::as_str)
+        .max_by_key(|p| p.len())
+        .unwrap_or("");
 
-    ctx.write_all(rows, list_args.include_flows)
+    let remainder = &catalog_name[base.len()..];
+    let idx = remainder.find(delimiter)?;
+    Some(catalog_name[..base.len() + idx + delimiter.len()].to_string())
 }
 
 pub async fn fetch_live_specs(
     ctx: &mut crate::CliContext,
-    mut list: List,
+    list: List,
 ) -> anyhow::Result<Vec<list_live_specs_query::SelectRef>> {
+    let (rows, _prefixes) = fetch_live_specs_resolved(ctx, list).await?;
+    Ok(rows)
+}
+
+/// Like [`fetch_live_specs`], but also returns the prefixes that were actually
+/// used for the query. These may have been auto-discovered when no `--name` or
+/// `--prefix` was given, and callers such as `--folders` browsing need them to
+/// collapse names relative to the real active prefix.
+async fn fetch_live_specs_resolved(
+    ctx: &mut crate::CliContext,
+    mut list: List,
+) -> anyhow::Result<(Vec<list_live_specs_query::SelectRef>, Vec<String>)> {
     use futures::TryStreamExt;
 
+    resolve_selectors(ctx, &mut list).await?;
+
+    let rows = fetch_paginated_live_specs(ctx.client.clone(), list.clone())
+        .try_collect()
+        .await?;
+
+    let rows = if list.closure {
+        expand_closure(ctx.client.clone(), &list, rows).await?
+    } else {
+        rows
+    };
+    Ok((rows, list.name_selector.prefix))
+}
+
+/// Fills in the selectors `list` will paginate with: when neither `--name` nor
+/// `--prefix` was given, the user's authorized prefixes are discovered
+/// automatically, and `--closure` forces `--flows` on so its graph edges are
+/// available.
+async fn resolve_selectors(
+    ctx: &mut crate::CliContext,
+    list: &mut List,
+) -> anyhow::Result<()> {
     if list.name_selector.name.is_empty() && list.name_selector.prefix.is_empty() {
         const DEFAULT_PREFIX_LIMIT: usize = 5;
 
@@ -75,9 +287,148 @@ pub async fn fetch_live_specs(
         list.name_selector.prefix = prefixes;
     }
 
-    fetch_paginated_live_specs(ctx.client.clone(), list)
-        .try_collect()
-        .await
+    // Expanding the closure requires the `reads_from`/`writes_to` edges, so
+    // force them on regardless of whether the user asked for `--flows`.
+    if list.closure {
+        list.include_flows = true;
+    }
+    Ok(())
+}
+
+/// A live-spec row reduced to the fields needed to walk the transitive
+/// closure. Keeping the graph walk off of the generated query types lets
+/// [`transitive_closure`] be unit tested without a live control plane.
+#[derive(Debug)]
+struct ClosureEntry {
+    catalog_name: String,
+    /// `false` when the spec's `live_spec` is `None` (it's being deleted).
+    present: bool,
+    /// Names referenced via `reads_from` / `writes_to`.
+    neighbors: Vec<String>,
+}
+
+fn closure_entry(row: &list_live_specs_query::SelectRef) -> ClosureEntry {
+    let mut neighbors = Vec::new();
+    if let Some(live_spec) = row.live_spec.as_ref() {
+        for conn in [live_spec.reads_from.as_ref(), live_spec.writes_to.as_ref()] {
+            for edge in conn.into_iter().flat_map(|c| c.edges.iter()) {
+                neighbors.push(edge.node.catalog_name.to_string());
+            }
+        }
+    }
+    ClosureEntry {
+        catalog_name: row.catalog_name.to_string(),
+        present: row.live_spec.is_some(),
+        neighbors,
+    }
+}
+
+/// Breadth-first expansion of a seed set over its `reads_from`/`writes_to`
+/// edges. Each round collects the neighbor names that aren't already present,
+/// hands them to `resolve` to be fetched, and repeats until no new names
+/// appear or `max_depth` rounds elapse. Names are deduped strictly, so cycles
+/// terminate, and neighbors whose spec is no longer `present` are dropped.
+/// Returns the catalog names in discovery order.
+async fn transitive_closure<F, Fut>(
+    seed: Vec<ClosureEntry>,
+    max_depth: u32,
+    mut resolve: F,
+) -> anyhow::Result<Vec<String>>
+where
+    F: FnMut(std::collections::BTreeSet<String>) -> Fut,
+    Fut: std::future::Future<Output = anyhow::Result<Vec<ClosureEntry>>>,
+{
+    use std::collections::BTreeSet;
+
+    let mut seen: BTreeSet<String> = BTreeSet::new();
+    let mut included: Vec<String> = Vec::new();
+    let mut frontier: Vec<ClosureEntry> = Vec::new();
+
+    for entry in seed {
+        if seen.insert(entry.catalog_name.clone()) {
+            included.push(entry.catalog_name.clone());
+            frontier.push(entry);
+        }
+    }
+
+    for _ in 0..max_depth {
+        let mut next: BTreeSet<String> = BTreeSet::new();
+        for entry in &frontier {
+            for neighbor in &entry.neighbors {
+                if !seen.contains(neighbor) {
+                    next.insert(neighbor.clone());
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+
+        let fetched = resolve(next).await?;
+        frontier = Vec::new();
+        for entry in fetched {
+            // Dedupe strictly by catalog_name so cycles terminate, and drop
+            // neighbors that no longer have a live spec.
+            if !seen.insert(entry.catalog_name.clone()) || !entry.present {
+                continue;
+            }
+            included.push(entry.catalog_name.clone());
+            frontier.push(entry);
+        }
+        if frontier.is_empty() {
+            break;
+        }
+    }
+
+    Ok(included)
+}
+
+/// Expands `rows` into its transitive closure, fetching newly-discovered
+/// neighbors by name. See [`List::closure`].
+async fn expand_closure(
+    client: flow_client::Client,
+    list: &List,
+    rows: Vec<list_live_specs_query::SelectRef>,
+) -> anyhow::Result<Vec<list_live_specs_query::SelectRef>> {
+    use futures::TryStreamExt;
+    use std::collections::BTreeMap;
+
+    // Keep every fetched row indexed by name so the closure order can be
+    // reassembled into `SelectRef`s once the walk completes.
+    let by_name = std::cell::RefCell::new(BTreeMap::new());
+    let seed: Vec<ClosureEntry> = rows.iter().map(closure_entry).collect();
+    for row in rows {
+        by_name.borrow_mut().insert(row.catalog_name.to_string(), row);
+    }
+
+    let client = &client;
+    let by_name = &by_name;
+    let order = transitive_closure(seed, list.closure_depth, move |names| {
+        let mut round = list.clone();
+        round.closure = false;
+        round.tolerate_missing_names = true;
+        round.name_selector.prefix = Vec::new();
+        round.name_selector.name = names.into_iter().collect();
+        async move {
+            let fetched: Vec<_> = fetch_paginated_live_specs(client.clone(), round)
+                .try_collect()
+                .await?;
+            let entries: Vec<ClosureEntry> = fetched.iter().map(closure_entry).collect();
+            for row in fetched {
+                by_name
+                    .borrow_mut()
+                    .insert(row.catalog_name.to_string(), row);
+            }
+            Ok(entries)
+        }
+    })
+    .await?;
+
+    let mut by_name = by_name.borrow_mut();
+    Ok(order
+        .into_iter()
+        .filter_map(|name| by_name.remove(&name))
+        .collect())
 }
 
 /// Accepts a listing of the users role grants, and returns a filtered list of
@@ -253,6 +604,38 @@ impl output::CliOutput for list_live_specs_query::SelectRef {
     }
 }
 
+impl output::CliOutput for ListEntry {
+    type TableAlt = bool;
+    type CellValue = String;
+
+    fn table_headers(flows: Self::TableAlt) -> Vec<&'static str> {
+        list_live_specs_query::SelectRef::table_headers(flows)
+    }
+
+    fn into_table_row(self, flows: Self::TableAlt) -> Vec<Self::CellValue> {
+        match self {
+            ListEntry::Spec(spec) => spec.into_table_row(flows),
+            ListEntry::CommonPrefix(prefix) => {
+                // Render a folder row: only the name and a "prefix" type marker
+                // are populated, the rest of the columns are left blank.
+                let mut row = vec![
+                    String::new(),
+                    prefix,
+                    String::from("prefix"),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ];
+                if flows {
+                    row.push(String::new());
+                    row.push(String::new());
+                }
+                row
+            }
+        }
+    }
+}
+
 fn format_flows(conn: Option<&list_live_specs_query::SelectConnection>) -> String {
     use itertools::Itertools;
 
@@ -264,54 +647,263 @@ fn format_flows(conn: Option<&list_live_specs_query::SelectConnection>) -> Strin
 
 /// Executes the graphql query for the given `list` arguments, making additional
 /// requests as necessary to read all of the results.
+///
+/// Each selector produced by [`to_vars`] is paginated as its own independent
+/// sub-stream, and the sub-streams are merged with up to `list.concurrency`
+/// paginating in flight at once, so several prefixes pay a max-of-latencies
+/// rather than a sum-of-latencies wait. Output ordering is not preserved, which
+/// is fine because results are sorted downstream.
 fn fetch_paginated_live_specs(
     client: flow_client::Client,
     list: List,
 ) -> impl futures::Stream<Item = anyhow::Result<list_live_specs_query::SelectRef>> + 'static {
+    use futures::stream::StreamExt;
+
     if list.name_selector.name.is_empty() && list.name_selector.prefix.is_empty() {
         panic!("fetch_paginated_live_specs requires either a name or prefix selector");
     }
+    let concurrency = list.concurrency.max(1);
+
+    let subs = to_vars(&list).into_iter().map(move |query_by| {
+        paginate_one_selector(client.clone(), list.clone(), query_by).boxed()
+    });
+
+    futures::stream::iter(subs).flatten_unordered(concurrency)
+}
+
+/// A single page of a paginated query: the items it yielded plus the cursor
+/// needed to request the next page, or `None` if this was the last page.
+struct Page<T> {
+    items: Vec<T>,
+    next_cursor: Option<String>,
+}
+
+/// Generic cursor-threading pagination: repeatedly calls `fetch_page` with the
+/// cursor returned by the previous page, yielding each page's items, until a
+/// page reports no successor. Decoupling the cursor loop from the GraphQL
+/// client lets it be unit tested against a mock page source.
+fn paginate<T, Q, F, Fut>(
+    query: Q,
+    mut fetch_page: F,
+) -> impl futures::Stream<Item = anyhow::Result<T>> + 'static
+where
+    T: 'static,
+    Q: Clone + 'static,
+    F: FnMut(Q, Option<String>) -> Fut + 'static,
+    Fut: std::future::Future<Output = anyhow::Result<Page<T>>> + 'static,
+{
+    coroutines::try_coroutine(move |mut co| async move {
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = fetch_page(query.clone(), cursor.take()).await?;
+            for item in page.items {
+                let () = co.yield_(item).await;
+            }
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Paginates a single [`LiveSpecsBy`] selector, threading its own page cursor
+/// independently of any other sub-stream. A by-name miss is a hard error
+/// (unless [`List::tolerate_missing_names`] is set), while a by-prefix miss is
+/// not an error: the row is yielded with no `live_spec`, since it indicates a
+/// spec that is being deleted.
+///
+/// [`LiveSpecsBy`]: list_live_specs_query::LiveSpecsBy
+fn paginate_one_selector(
+    client: flow_client::Client,
+    list: List,
+    query_by: list_live_specs_query::LiveSpecsBy,
+) -> impl futures::Stream<Item = anyhow::Result<list_live_specs_query::SelectRef>> + 'static {
     // Use a smaller batch size if we're including the models, since they can be quite large.
     let page_size = if list.include_models { 50 } else { 200 };
-    let is_by_name = !list.name_selector.name.is_empty();
-    coroutines::try_coroutine(|mut co| async move {
-        for query_by in to_vars(&list) {
-            let mut cursor: Option<String> = None;
-
-            'pagination: loop {
-                let vars = list_live_specs_query::Variables {
-                    by: query_by.clone(),
-                    after: cursor.take(),
-                    first: Some(page_size),
-                    include_models: list.include_models,
-                    include_flows: list.include_flows,
-                    include_last_publication: list.include_last_publication,
-                };
-                let resp = post_graphql::<ListLiveSpecsQuery>(&client, vars)
-                    .await
-                    .context("failed to fetch live specs")?;
-
-                for edge in resp.live_specs.edges {
-                    // Only error when the user explicitly requested the spec by
-                    // name and it does not exist. Otherwise, a missing live spec
-                    // just indicates that the spec is in the process of being
-                    // deleted.
-                    if edge.node.live_spec.is_none() && is_by_name {
-                        anyhow::bail!("no live spec exists for name: '{}'", edge.node.catalog_name);
-                    }
-                    let () = co.yield_(edge.node).await;
-                }
-                if !resp.live_specs.page_info.has_next_page {
-                    break 'pagination;
+    let is_by_name = matches!(query_by, list_live_specs_query::LiveSpecsBy::Names(_));
+
+    paginate(query_by, move |query_by, cursor| {
+        let client = client.clone();
+        let list = list.clone();
+        async move {
+            let vars = list_live_specs_query::Variables {
+                by: query_by,
+                after: cursor,
+                first: Some(page_size),
+                include_models: list.include_models,
+                include_flows: list.include_flows,
+                include_last_publication: list.include_last_publication,
+            };
+            let resp = post_graphql::<ListLiveSpecsQuery>(&client, vars)
+                .await
+                .context("failed to fetch live specs")?;
+
+            let mut items = Vec::with_capacity(resp.live_specs.edges.len());
+            for edge in resp.live_specs.edges {
+                // Only error when the user explicitly requested the spec by
+                // name and it does not exist. Otherwise, a missing live spec
+                // just indicates that the spec is in the process of being
+                // deleted.
+                if edge.node.live_spec.is_none() && is_by_name && !list.tolerate_missing_names {
+                    anyhow::bail!("no live spec exists for name: '{}'", edge.node.catalog_name);
                 }
-                cursor = resp.live_specs.page_info.end_cursor;
-                assert!(cursor.is_some(), "liveSpecs pageInfo missing endCursor");
+                items.push(edge.node);
             }
+
+            let next_cursor = if resp.live_specs.page_info.has_next_page {
+                let cursor = resp.live_specs.page_info.end_cursor;
+                assert!(cursor.is_some(), "liveSpecs pageInfo missing endCursor");
+                cursor
+            } else {
+                None
+            };
+            Ok(Page { items, next_cursor })
         }
-        Ok(())
     })
 }
 
+/// Streams the matched live specs into an S3-compatible bucket, one object per
+/// catalog name, reusing the same model serialization as `--models`. Objects
+/// are uploaded as the pagination stream yields them, up to `--concurrency` in
+/// flight, so memory stays bounded regardless of tenant size.
+async fn export_live_specs_to_s3(
+    ctx: &mut crate::CliContext,
+    mut list: List,
+) -> anyhow::Result<()> {
+    use futures::stream::{StreamExt, TryStreamExt};
+
+    let target = list
+        .export_s3
+        .clone()
+        .expect("export_live_specs_to_s3 called without --export-s3");
+    let (bucket, key_prefix) = parse_s3_url(&target)?;
+
+    // The serialized model is the object body, so we must fetch it.
+    list.include_models = true;
+    resolve_selectors(ctx, &mut list).await?;
+
+    let client = build_s3_client(&list).await?;
+    let concurrency = list.concurrency.max(1);
+    let uploaded = std::sync::atomic::AtomicUsize::new(0);
+
+    // `--closure` requires materializing the full transitive set before we know
+    // what to export, so in that case we expand first and stream from the
+    // result; otherwise we stream straight from pagination to keep memory
+    // bounded.
+    let specs = if list.closure {
+        let rows = fetch_paginated_live_specs(ctx.client.clone(), list.clone())
+            .try_collect()
+            .await?;
+        let rows = expand_closure(ctx.client.clone(), &list, rows).await?;
+        futures::stream::iter(rows.into_iter().map(Ok::<_, anyhow::Error>)).boxed()
+    } else {
+        fetch_paginated_live_specs(ctx.client.clone(), list).boxed()
+    };
+
+    specs
+        .try_for_each_concurrent(concurrency, |row| {
+            let client = &client;
+            let bucket = bucket.as_str();
+            let key_prefix = key_prefix.as_str();
+            let uploaded = &uploaded;
+            async move {
+                // Specs mid-deletion have no model to write; skip them.
+                let Some(live_spec) = row.live_spec.as_ref() else {
+                    return Ok(());
+                };
+                let Some(model) = live_spec.model.as_ref() else {
+                    return Ok(());
+                };
+
+                let key = object_key(
+                    key_prefix,
+                    row.catalog_name.as_str(),
+                    live_spec.catalog_type.as_ref(),
+                );
+                client
+                    .put_object()
+                    .bucket(bucket)
+                    .key(&key)
+                    .body(aws_sdk_s3::primitives::ByteStream::from(
+                        model.get().as_bytes().to_vec(),
+                    ))
+                    .content_type("application/json")
+                    .send()
+                    .await
+                    .with_context(|| {
+                        format!("failed to export '{}' to s3://{bucket}/{key}", row.catalog_name)
+                    })?;
+
+                uploaded.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                Ok(())
+            }
+        })
+        .await?;
+
+    tracing::info!(
+        count = uploaded.load(std::sync::atomic::Ordering::Relaxed),
+        "exported live specs to s3://{bucket}/{key_prefix}"
+    );
+    Ok(())
+}
+
+/// Splits an `s3://bucket/prefix` URL into its bucket and (possibly empty) key
+/// prefix, with any trailing delimiter trimmed.
+fn parse_s3_url(url: &str) -> anyhow::Result<(String, String)> {
+    let rest = url
+        .strip_prefix("s3://")
+        .with_context(|| format!("--export-s3 must be an s3:// URL, got '{url}'"))?;
+    let (bucket, prefix) = rest.split_once('/').unwrap_or((rest, ""));
+    if bucket.is_empty() {
+        anyhow::bail!("--export-s3 URL '{url}' is missing a bucket name");
+    }
+    Ok((bucket.to_string(), prefix.trim_end_matches('/').to_string()))
+}
+
+/// Builds the object key for a spec: the catalog name, suffixed with its type
+/// and a `.json` extension (e.g. `acmeCo/prod/anvils.capture.json`), under the
+/// optional `key_prefix`.
+fn object_key(key_prefix: &str, catalog_name: &str, catalog_type: &str) -> String {
+    let leaf = format!("{catalog_name}.{catalog_type}.json");
+    if key_prefix.is_empty() {
+        leaf
+    } else {
+        format!("{key_prefix}/{leaf}")
+    }
+}
+
+/// Constructs an S3 client for `--export-s3`, honoring the optional endpoint,
+/// region, and static credentials so that non-AWS gateways work. When no
+/// credentials are provided, the ambient AWS credential chain is used.
+async fn build_s3_client(list: &List) -> anyhow::Result<aws_sdk_s3::Client> {
+    let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
+    if let Some(region) = &list.export_s3_region {
+        loader = loader.region(aws_sdk_s3::config::Region::new(region.clone()));
+    }
+    if let (Some(id), Some(secret)) = (
+        &list.export_s3_access_key_id,
+        &list.export_s3_secret_access_key,
+    ) {
+        loader = loader.credentials_provider(aws_sdk_s3::config::Credentials::new(
+            id.clone(),
+            secret.clone(),
+            None,
+            None,
+            "flowctl-export",
+        ));
+    }
+    let shared = loader.load().await;
+
+    let mut builder = aws_sdk_s3::config::Builder::from(&shared);
+    if let Some(endpoint) = &list.export_s3_endpoint {
+        // Path-style addressing is required by most non-AWS gateways.
+        builder = builder.endpoint_url(endpoint).force_path_style(true);
+    }
+    Ok(aws_sdk_s3::Client::from_conf(builder.build()))
+}
+
 fn to_vars(list: &List) -> Vec<list_live_specs_query::LiveSpecsBy> {
     let data_plane_name = list
         .data_plane_selector
@@ -379,4 +971,260 @@ mod test {
         let fail_result = filter_default_prefixes(roles, 2);
         assert!(fail_result.is_err());
     }
+
+    fn entry(name: &str, present: bool, neighbors: &[&str]) -> ClosureEntry {
+        ClosureEntry {
+            catalog_name: name.to_string(),
+            present,
+            neighbors: neighbors.iter().map(|n| n.to_string()).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transitive_closure_walks_edges() {
+        // Graph: mat -> collection -> capture, so a single seed materialization
+        // should pull in the collection it reads and that collection's capture.
+        let graph = |name: &str| match name {
+            "acmeCo/collection" => Some(entry("acmeCo/collection", true, &["acmeCo/capture"])),
+            "acmeCo/capture" => Some(entry("acmeCo/capture", true, &[])),
+            _ => None,
+        };
+        let seed = vec![entry("acmeCo/mat", true, &["acmeCo/collection"])];
+
+        let order = transitive_closure(seed, 10, |names| {
+            let fetched: Vec<_> = names.iter().filter_map(|n| graph(n)).collect();
+            std::future::ready(Ok(fetched))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            order,
+            vec![
+                "acmeCo/mat".to_string(),
+                "acmeCo/collection".to_string(),
+                "acmeCo/capture".to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transitive_closure_terminates_on_cycle() {
+        // a -> b -> a is a cycle; strict dedupe must terminate it.
+        let graph = |name: &str| match name {
+            "a" => Some(entry("a", true, &["b"])),
+            "b" => Some(entry("b", true, &["a"])),
+            _ => None,
+        };
+        let seed = vec![entry("a", true, &["b"])];
+
+        let order = transitive_closure(seed, 10, |names| {
+            let fetched: Vec<_> = names.iter().filter_map(|n| graph(n)).collect();
+            std::future::ready(Ok(fetched))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(order, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_transitive_closure_drops_missing_specs() {
+        // The referenced collection is mid-deletion (`present: false`): it must
+        // be dropped from the output and must not be expanded further.
+        let graph = |name: &str| match name {
+            "acmeCo/gone" => Some(entry("acmeCo/gone", false, &["acmeCo/unreachable"])),
+            _ => None,
+        };
+        let seed = vec![entry("acmeCo/mat", true, &["acmeCo/gone"])];
+
+        let order = transitive_closure(seed, 10, |names| {
+            let fetched: Vec<_> = names.iter().filter_map(|n| graph(n)).collect();
+            std::future::ready(Ok(fetched))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(order, vec!["acmeCo/mat".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_transitive_closure_respects_depth_cap() {
+        // a -> b -> c, but a depth cap of 1 stops after the first expansion.
+        let graph = |name: &str| match name {
+            "b" => Some(entry("b", true, &["c"])),
+            "c" => Some(entry("c", true, &[])),
+            _ => None,
+        };
+        let seed = vec![entry("a", true, &["b"])];
+
+        let order = transitive_closure(seed, 1, |names| {
+            let fetched: Vec<_> = names.iter().filter_map(|n| graph(n)).collect();
+            std::future::ready(Ok(fetched))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(order, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn test_collapse_name() {
+        let prefixes = vec!["acmeCo/".to_string()];
+
+        // A nested name collapses to the next segment.
+        assert_eq!(
+            collapse_name("acmeCo/prod/anvils", &prefixes, "/"),
+            Some("acmeCo/prod/".to_string())
+        );
+        // A deeper nested name still collapses at the first segment.
+        assert_eq!(
+            collapse_name("acmeCo/prod/anvils/events", &prefixes, "/"),
+            Some("acmeCo/prod/".to_string())
+        );
+        // A trailing delimiter collapses to the name itself.
+        assert_eq!(
+            collapse_name("acmeCo/prod/", &prefixes, "/"),
+            Some("acmeCo/prod/".to_string())
+        );
+        // A name with no delimiter beyond the active prefix is a leaf.
+        assert_eq!(collapse_name("acmeCo/anvils", &prefixes, "/"), None);
+
+        // With no active prefix, collapsing starts at the top of the name.
+        assert_eq!(
+            collapse_name("acmeCo/anvils", &[], "/"),
+            Some("acmeCo/".to_string())
+        );
+
+        // The longest matching prefix wins: under `acmeCo/prod/`, the name
+        // `acmeCo/prod/anvils` has no further delimiter and is a leaf.
+        let nested = vec!["acmeCo/".to_string(), "acmeCo/prod/".to_string()];
+        assert_eq!(collapse_name("acmeCo/prod/anvils", &nested, "/"), None);
+    }
+
+    #[test]
+    fn test_to_vars_covers_every_selector() {
+        // Every prefix becomes its own sub-stream selector, and all explicit
+        // names are batched into a single `Names` selector.
+        let mut list = List {
+            concurrency: 4,
+            ..Default::default()
+        };
+        list.name_selector.prefix = vec!["acmeCo/".to_string(), "wileyCo/".to_string()];
+        list.name_selector.name = vec!["coyoteCo/traps".to_string()];
+
+        let vars = to_vars(&list);
+
+        let prefixes: Vec<String> = vars
+            .iter()
+            .filter_map(|by| match by {
+                list_live_specs_query::LiveSpecsBy::PrefixAndType(p) => Some(p.prefix.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(prefixes, vec!["acmeCo/".to_string(), "wileyCo/".to_string()]);
+
+        let names: Vec<Vec<String>> = vars
+            .iter()
+            .filter_map(|by| match by {
+                list_live_specs_query::LiveSpecsBy::Names(names) => {
+                    Some(names.iter().map(|n| n.to_string()).collect())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(names, vec![vec!["coyoteCo/traps".to_string()]]);
+    }
+
+    #[tokio::test]
+    async fn test_paginated_sub_streams_thread_cursors_independently() {
+        use futures::stream::{StreamExt, TryStreamExt};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        fn page(items: &[&str], next: Option<&str>) -> Page<String> {
+            Page {
+                items: items.iter().map(|s| s.to_string()).collect(),
+                next_cursor: next.map(str::to_string),
+            }
+        }
+
+        // A mock page source: two independent selectors, each paginated across
+        // two pages keyed by cursor. Every (selector, cursor) request is
+        // recorded so we can assert cursors are threaded per sub-stream and no
+        // page is dropped.
+        let requests = Rc::new(RefCell::new(Vec::<(String, Option<String>)>::new()));
+
+        let sub_stream = |selector: &str| {
+            let requests = requests.clone();
+            paginate(selector.to_string(), move |selector: String, cursor: Option<String>| {
+                requests.borrow_mut().push((selector.clone(), cursor.clone()));
+                let page = match (selector.as_str(), cursor.as_deref()) {
+                    ("acmeCo/", None) => page(&["acmeCo/a", "acmeCo/b"], Some("acme-p1")),
+                    ("acmeCo/", Some("acme-p1")) => page(&["acmeCo/c"], None),
+                    ("wileyCo/", None) => page(&["wileyCo/x"], Some("wiley-p1")),
+                    ("wileyCo/", Some("wiley-p1")) => page(&["wileyCo/y"], None),
+                    other => panic!("unexpected page request: {other:?}"),
+                };
+                std::future::ready(Ok(page))
+            })
+            .boxed_local()
+        };
+
+        // Merge the sub-streams exactly as `fetch_paginated_live_specs` does.
+        let subs = vec![sub_stream("acmeCo/"), sub_stream("wileyCo/")];
+        let mut items: Vec<String> = futures::stream::iter(subs)
+            .flatten_unordered(2)
+            .try_collect()
+            .await
+            .unwrap();
+        items.sort();
+
+        // Every page from both sub-streams is present, none dropped.
+        assert_eq!(
+            items,
+            vec![
+                "acmeCo/a".to_string(),
+                "acmeCo/b".to_string(),
+                "acmeCo/c".to_string(),
+                "wileyCo/x".to_string(),
+                "wileyCo/y".to_string(),
+            ]
+        );
+
+        // Each selector advanced through its own cursor sequence independently:
+        // a first request with no cursor, then a request carrying that
+        // selector's own `end_cursor`.
+        let requests = requests.borrow();
+        assert!(requests.contains(&("acmeCo/".to_string(), None)));
+        assert!(requests.contains(&("acmeCo/".to_string(), Some("acme-p1".to_string()))));
+        assert!(requests.contains(&("wileyCo/".to_string(), None)));
+        assert!(requests.contains(&("wileyCo/".to_string(), Some("wiley-p1".to_string()))));
+        // A cursor is never crossed between selectors.
+        assert!(!requests.contains(&("acmeCo/".to_string(), Some("wiley-p1".to_string()))));
+        assert!(!requests.contains(&("wileyCo/".to_string(), Some("acme-p1".to_string()))));
+    }
+
+    #[test]
+    fn test_parse_s3_url_and_object_key() {
+        assert_eq!(
+            parse_s3_url("s3://my-bucket/snapshots/").unwrap(),
+            ("my-bucket".to_string(), "snapshots".to_string())
+        );
+        assert_eq!(
+            parse_s3_url("s3://my-bucket").unwrap(),
+            ("my-bucket".to_string(), String::new())
+        );
+        assert!(parse_s3_url("https://my-bucket/x").is_err());
+        assert!(parse_s3_url("s3:///no-bucket").is_err());
+
+        assert_eq!(
+            object_key("snapshots", "acmeCo/prod/anvils", "capture"),
+            "snapshots/acmeCo/prod/anvils.capture.json"
+        );
+        assert_eq!(
+            object_key("", "acmeCo/prod/anvils", "collection"),
+            "acmeCo/prod/anvils.collection.json"
+        );
+    }
 }